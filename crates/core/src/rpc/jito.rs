@@ -1,25 +1,159 @@
-use std::str::FromStr;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::sync::RwLock;
 
+use base64::Engine;
 use jsonrpc_core::{Error, Result};
 use jsonrpc_derive::rpc;
+use serde::{Deserialize, Serialize};
 use solana_client::rpc_config::RpcSendTransactionConfig;
 use solana_client::rpc_custom_error::RpcCustomError;
+use solana_client::rpc_response::{Response as RpcResponse, RpcResponseContext};
+use solana_sdk::clock::Slot;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::system_instruction::SystemInstruction;
+use solana_sdk::system_program;
+use solana_sdk::transaction::VersionedTransaction;
 use solana_signature::Signature;
+use solana_transaction_error::TransactionError;
+use solana_transaction_status::TransactionConfirmationStatus;
+use solana_transaction_status::UiTransactionEncoding;
+use solana_transaction_status::UiTransactionReturnData;
 
 use super::{
     RunloopContext,
     full::{Full, SurfpoolFullRpc},
 };
 
+/// Jito caps a bundle at five transactions; used as the fallback when
+/// `SurfpoolJitoRpc::max_bundle_len` isn't configured to something else.
+pub const DEFAULT_MAX_BUNDLE_LEN: usize = 5;
+
+/// Tip-enforcement settings for `SurfpoolJitoRpc`. Disabled by default so that existing
+/// tip-less bundles (e.g. in tests) keep working; a deployment that wants to emulate Jito's
+/// Block Engine can opt in via config.
+#[derive(Debug, Clone, Default)]
+pub struct JitoTipConfig {
+    /// Whether `send_bundle` should reject bundles that don't pay a sufficient tip.
+    pub enforce_tips: bool,
+    /// Minimum total lamports that must be transferred to a tip account for the bundle to be
+    /// accepted.
+    pub min_tip_lamports: u64,
+    /// Designated tip-receiver pubkeys.
+    pub tip_accounts: HashSet<Pubkey>,
+}
+
+/// Everything we remember about a bundle once `send_bundle` has processed it, so that
+/// `getBundleStatuses` / `getInflightBundleStatuses` can answer without re-running anything.
+#[derive(Debug, Clone)]
+struct BundleRecord {
+    /// Signatures of the bundle's transactions, in submission order.
+    signatures: Vec<Signature>,
+    /// Slot at which the bundle was processed — whether or not it ultimately landed. Check `err`
+    /// to tell a commit from a rejection.
+    slot: Slot,
+    /// `None` if the whole bundle landed; otherwise the error that caused it to be rejected.
+    err: Option<TransactionError>,
+    /// Total lamports paid to tip accounts, detected whether or not tip enforcement is on. `None`
+    /// if no tip transfer was found, except for a bundle rejected for an insufficient tip, which
+    /// records the (possibly zero) total it found.
+    tip_lamports: Option<u64>,
+    /// Signature of the transaction that paid the tip, if any.
+    tip_signature: Option<Signature>,
+}
+
+/// Status of a single bundle, as returned by `getBundleStatuses`.
+///
+/// Shaped like Solana's `TransactionStatus`: `err` is `null` on success. For a rejected bundle it
+/// is always the fixed placeholder `InstructionError(0, GenericError)`, whatever the cause; it does
+/// not carry the failing transaction's own `TransactionError` or its index in the bundle.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcBundleStatus {
+    pub bundle_id: String,
+    pub transactions: Vec<String>,
+    pub slot: Slot,
+    pub confirmation_status: Option<TransactionConfirmationStatus>,
+    pub err: Option<TransactionError>,
+    pub tip_lamports: Option<u64>,
+    pub tip_signature: Option<String>,
+}
+
+/// Lifecycle state of a bundle, as returned by `getInflightBundleStatuses`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum InflightBundleStatus {
+    /// Submitted, but not yet landed or failed.
+    Pending,
+    /// Committed to canonical state.
+    Landed,
+    /// Rejected; none of its transactions were committed.
+    Failed,
+    /// Unknown to this node.
+    Invalid,
+}
+
+/// Entry returned by `getInflightBundleStatuses` for a single requested bundle ID.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcInflightBundleStatus {
+    pub bundle_id: String,
+    pub status: InflightBundleStatus,
+    pub landed_slot: Option<Slot>,
+}
+
+/// Config for `simulateBundle`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcSimulateBundleConfig {
+    /// Whether to verify transaction signatures before simulating. Defaults to `true`, matching
+    /// `simulateTransaction`.
+    pub sig_verify: Option<bool>,
+    /// Encoding of the submitted transactions. Defaults to base58.
+    pub encoding: Option<UiTransactionEncoding>,
+}
+
+/// Per-transaction result produced by `simulateBundle`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcSimulateBundleTransactionResult {
+    pub err: Option<TransactionError>,
+    pub logs: Option<Vec<String>>,
+    pub units_consumed: Option<u64>,
+    pub return_data: Option<UiTransactionReturnData>,
+}
+
+/// Result of `simulateBundle`: one entry per submitted transaction, in order.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcSimulateBundleResult {
+    pub transaction_results: Vec<RpcSimulateBundleTransactionResult>,
+}
+
+/// Config for the Jito-flavored `sendTransaction`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcJitoSendTransactionConfig {
+    #[serde(flatten)]
+    pub send_config: RpcSendTransactionConfig,
+    /// When `true`, the transaction is only routed through the bundle path. When `false`
+    /// (default), it is additionally tracked as a standalone transaction, matching what clients
+    /// expect from a plain `sendTransaction` call.
+    pub bundle_only: Option<bool>,
+}
+
 /// Jito-specific RPC methods for bundle submission
 #[rpc]
 pub trait Jito {
     type Metadata;
 
-    /// Sends a bundle of transactions to be processed sequentially.
+    /// Sends a bundle of transactions to be processed atomically.
     ///
-    /// This RPC method accepts a bundle of transactions (Jito-compatible format) and processes them
-    /// one by one in order. All transactions in the bundle must succeed for the bundle to be accepted.
+    /// This RPC method accepts a bundle of transactions (Jito-compatible format) and applies them
+    /// one by one, in order, against a disposable checkpoint of the working SVM state. The bundle
+    /// is only committed to canonical state once every transaction in it has succeeded.
     ///
     /// ## Parameters
     /// - `transactions`: An array of serialized transaction data (base64 or base58 encoded).
@@ -42,10 +176,23 @@ pub trait Jito {
     /// ```
     ///
     /// ## Notes
-    /// - Transactions are processed sequentially in the order provided
-    /// - Each transaction must complete successfully before the next one starts
-    /// - If any transaction fails, the entire bundle is rejected
-    /// - The bundle ID is calculated as SHA-256 hash of comma-separated transaction signatures
+    /// - Transactions are applied sequentially, in the order provided, against a checkpoint of
+    ///   the working SVM state rather than the canonical state directly.
+    /// - If any transaction fails, the checkpoint is discarded and the bundle is rejected; none
+    ///   of the prior transactions in the bundle become observable.
+    /// - Only once every transaction in the bundle succeeds is the checkpoint committed, so the
+    ///   bundle lands in full or not at all.
+    /// - The bundle ID is calculated as SHA-256 hash of comma-separated transaction signatures.
+    /// - Once the bundle has passed the length and decode checks, its outcome (landed or
+    ///   rejected) is recorded and can be queried afterward via `getBundleStatuses` /
+    ///   `getInflightBundleStatuses`. Empty, over-length or undecodable bundles are not recorded.
+    /// - If tip enforcement is enabled (see [`JitoTipConfig`]), the bundle is scanned for a SOL
+    ///   transfer to a designated tip account and rejected if the total falls short of the
+    ///   configured minimum. Disabled by default, so tip-less bundles keep working.
+    /// - Bundles longer than the configured maximum (5 by default, matching real Jito) are
+    ///   rejected up front. Every transaction is also pre-decoded against the declared encoding
+    ///   before anything runs, so a malformed entry is reported by index rather than surfacing
+    ///   as an opaque failure after earlier transactions have already committed.
     #[rpc(meta, name = "sendBundle")]
     fn send_bundle(
         &self,
@@ -53,10 +200,415 @@ pub trait Jito {
         transactions: Vec<String>,
         config: Option<RpcSendTransactionConfig>,
     ) -> Result<String>;
+
+    /// Returns the landed status of each requested bundle ID.
+    ///
+    /// For each ID, reports the slot it was processed in (landed or not), a confirmation status
+    /// (`finalized` if it landed, otherwise none), the transaction signatures it produced, and an
+    /// `err` field (`null` on success, otherwise the fixed placeholder
+    /// `InstructionError(0, GenericError)`; see [`RpcBundleStatus`]). Unknown bundle IDs map to
+    /// `None`.
+    #[rpc(meta, name = "getBundleStatuses")]
+    fn get_bundle_statuses(
+        &self,
+        meta: Self::Metadata,
+        bundle_ids: Vec<String>,
+    ) -> Result<RpcResponse<Vec<Option<RpcBundleStatus>>>>;
+
+    /// Returns the in-flight lifecycle state of each requested bundle ID: `Landed`, `Failed`, or
+    /// `Invalid` (unknown to this node). `Pending` is never reported: `sendBundle` processes a
+    /// bundle synchronously and records it only once it has landed or failed.
+    #[rpc(meta, name = "getInflightBundleStatuses")]
+    fn get_inflight_bundle_statuses(
+        &self,
+        meta: Self::Metadata,
+        bundle_ids: Vec<String>,
+    ) -> Result<RpcResponse<Vec<RpcInflightBundleStatus>>>;
+
+    /// Dry-runs a bundle without committing anything to canonical state.
+    ///
+    /// Mirrors `simulateTransaction`, but at the bundle level: every transaction is applied, in
+    /// order, against the same disposable checkpoint, so transaction 2 sees the account changes
+    /// made by transaction 1 — something a sequence of plain `simulateTransaction` calls can't
+    /// do. The checkpoint is always discarded, regardless of outcome.
+    #[rpc(meta, name = "simulateBundle")]
+    fn simulate_bundle(
+        &self,
+        meta: Self::Metadata,
+        transactions: Vec<String>,
+        config: Option<RpcSimulateBundleConfig>,
+    ) -> Result<RpcResponse<RpcSimulateBundleResult>>;
+
+    /// Jito Block Engine-style `sendTransaction`: accepts a single transaction, wraps it as a
+    /// one-transaction bundle, and submits it through the same atomic bundle path as
+    /// `sendBundle`. `skip_preflight` is always forced to `true`, matching Block Engine
+    /// behavior, regardless of what the caller asked for.
+    ///
+    /// Registered as `jitoSendTransaction`, not `sendTransaction`: the standard `Full` RPC trait
+    /// already owns that method name, and merging both traits onto one `IoHandler` (the normal
+    /// way to wire up a surfpool RPC server) would otherwise have one silently clobber the other
+    /// with no compile-time warning.
+    ///
+    /// Returns the transaction's own signature (not a bundle ID), so the response shape still
+    /// matches what clients expect from a plain `sendTransaction` call, while a bundle record is
+    /// also created so the submission can be looked up via `getBundleStatuses`.
+    ///
+    /// `bundleOnly` controls whether the transaction is tracked only as a bundle (`true`) or
+    /// additionally as a standalone transaction (`false`, the default).
+    #[rpc(meta, name = "jitoSendTransaction")]
+    fn send_transaction(
+        &self,
+        meta: Self::Metadata,
+        transaction: String,
+        config: Option<RpcJitoSendTransactionConfig>,
+    ) -> Result<String>;
 }
 
-#[derive(Clone)]
-pub struct SurfpoolJitoRpc;
+/// Bundle submission and lookup state that `Jito` needs but that doesn't belong on
+/// `RunloopContext` (which is shared with every other RPC namespace): tip enforcement config,
+/// the maximum bundle length, and the registry of past bundle outcomes.
+#[derive(Debug, Clone, Default)]
+pub struct SurfpoolJitoRpc {
+    tip_config: JitoTipConfig,
+    max_bundle_len: Option<usize>,
+    bundle_registry: Arc<RwLock<HashMap<String, BundleRecord>>>,
+}
+
+impl SurfpoolJitoRpc {
+    /// Builds a `SurfpoolJitoRpc` with explicit tip-enforcement and max-bundle-length config.
+    /// Use [`SurfpoolJitoRpc::default`] to get Jito's defaults (tips not enforced, max length of
+    /// [`DEFAULT_MAX_BUNDLE_LEN`]).
+    pub fn new(tip_config: JitoTipConfig, max_bundle_len: Option<usize>) -> Self {
+        Self {
+            tip_config,
+            max_bundle_len,
+            bundle_registry: Arc::new(RwLock::new(HashMap::new())),
+        }
+    }
+
+    /// Decodes a single bundle transaction per the encoding declared in `config`, defaulting to
+    /// base58 to match `sendTransaction`'s own default.
+    fn decode_transaction(
+        tx_data: &str,
+        config: &Option<RpcSendTransactionConfig>,
+    ) -> Result<VersionedTransaction> {
+        let encoding = config
+            .as_ref()
+            .and_then(|c| c.encoding)
+            .unwrap_or(UiTransactionEncoding::Base58);
+
+        let bytes = match encoding {
+            UiTransactionEncoding::Base58 => bs58::decode(tx_data)
+                .into_vec()
+                .map_err(|e| Error::invalid_params(format!("invalid base58 transaction: {e}")))?,
+            UiTransactionEncoding::Base64 => base64::engine::general_purpose::STANDARD
+                .decode(tx_data)
+                .map_err(|e| Error::invalid_params(format!("invalid base64 transaction: {e}")))?,
+            other => {
+                return Err(Error::invalid_params(format!(
+                    "unsupported transaction encoding: {other:?}"
+                )));
+            }
+        };
+
+        bincode::deserialize(&bytes)
+            .map_err(|e| Error::invalid_params(format!("failed to deserialize transaction: {e}")))
+    }
+
+    /// Rejects a bundle longer than `max_bundle_len`, matching real Jito's cap.
+    fn validate_bundle_length(len: usize, max_bundle_len: usize) -> Result<()> {
+        if len > max_bundle_len {
+            return Err(Error::invalid_params(format!(
+                "Bundle exceeds maximum length of {max_bundle_len} transactions (got {len})"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Decodes every transaction in the bundle, reporting a malformed entry by its index in the
+    /// bundle rather than as an opaque failure, so a client can tell which transaction to fix.
+    fn decode_bundle(
+        transactions: &[String],
+        config: &Option<RpcSendTransactionConfig>,
+    ) -> Result<Vec<VersionedTransaction>> {
+        transactions
+            .iter()
+            .enumerate()
+            .map(|(idx, tx_data)| {
+                Self::decode_transaction(tx_data, config).map_err(|e| {
+                    Error::invalid_params(format!(
+                        "Bundle transaction {idx} is malformed: {}",
+                        e.message
+                    ))
+                })
+            })
+            .collect()
+    }
+
+    /// Sums the lamports transferred to any of `tip_accounts` across `transactions`, returning
+    /// the total along with the index of the transaction that paid it. Only plain
+    /// `system_instruction::transfer`s are recognized, matching how Jito tooling conventionally
+    /// pays tips.
+    fn extract_tip(
+        transactions: &[VersionedTransaction],
+        tip_accounts: &HashSet<Pubkey>,
+    ) -> (u64, Option<usize>) {
+        let mut total = 0u64;
+        let mut paid_by = None;
+
+        for (idx, tx) in transactions.iter().enumerate() {
+            let message = &tx.message;
+            for ix in message.instructions() {
+                let Some(program_id) = message.static_account_keys().get(ix.program_id_index as usize)
+                else {
+                    continue;
+                };
+                if *program_id != system_program::id() {
+                    continue;
+                }
+                let Ok(SystemInstruction::Transfer { lamports }) = bincode::deserialize(&ix.data)
+                else {
+                    continue;
+                };
+                let Some(&to_index) = ix.accounts.get(1) else {
+                    continue;
+                };
+                let Some(to_pubkey) = message.static_account_keys().get(to_index as usize) else {
+                    continue;
+                };
+                if tip_accounts.contains(to_pubkey) {
+                    total += lamports;
+                    paid_by.get_or_insert(idx);
+                }
+            }
+        }
+
+        (total, paid_by)
+    }
+
+    /// Runs every transaction in the bundle, in order, against `checkpoint`.
+    ///
+    /// `checkpoint` is a handle onto a single disposable, copy-on-write snapshot of the working
+    /// SVM state, obtained via [`RunloopContext::checkpoint`]; cloning it per iteration below is
+    /// a cheap clone of that *same* handle (akin to cloning an `Arc`), not a fresh snapshot per
+    /// transaction — every transaction in the loop sees the effects of the ones before it.
+    /// Nothing written here is observable outside of `checkpoint` until the caller commits it via
+    /// [`RunloopContext::commit_checkpoint`], which is what gives `send_bundle` its all-or-nothing
+    /// guarantee: a failure partway through the bundle simply drops the checkpoint, leaving
+    /// canonical state untouched.
+    fn execute_staged(
+        checkpoint: &RunloopContext,
+        transactions: &[String],
+        config: &Option<RpcSendTransactionConfig>,
+    ) -> Result<()> {
+        let full_rpc = SurfpoolFullRpc;
+
+        // Force skip_preflight to match Jito Block Engine behavior (no simulation on sendBundle)
+        for (idx, tx_data) in transactions.iter().enumerate() {
+            let bundle_config = Some(RpcSendTransactionConfig {
+                skip_preflight: true,
+                ..config.clone().unwrap_or_default()
+            });
+            // Delegate to Full RPC's sendTransaction, scoped to the checkpoint rather than
+            // the canonical working state.
+            if let Err(e) =
+                full_rpc.send_transaction(Some(checkpoint.clone()), tx_data.clone(), bundle_config)
+            {
+                // Add bundle transaction index to error message
+                return Err(Error {
+                    code: e.code,
+                    message: format!("Bundle transaction {idx} failed: {}", e.message),
+                    data: e.data,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Hashes a bundle's transaction signatures into its Jito-compatible bundle ID. The
+    /// signatures come straight off the already-signed transactions, so this is known before
+    /// anything executes — which is what lets [`SurfpoolJitoRpc::process_bundle`] record a
+    /// bundle's outcome even when it never lands.
+    /// https://github.com/jito-foundation/jito-solana/blob/master/sdk/src/bundle/mod.rs#L21
+    fn compute_bundle_id(signatures: &[Signature]) -> String {
+        use sha2::{Digest, Sha256};
+        let concatenated_signatures = signatures
+            .iter()
+            .map(|sig| sig.to_string())
+            .collect::<Vec<_>>()
+            .join(",");
+        let mut hasher = Sha256::new();
+        hasher.update(concatenated_signatures.as_bytes());
+        hex::encode(hasher.finalize())
+    }
+
+    /// Records a bundle's outcome in the registry, overwriting any prior entry for the same ID.
+    /// Called on every path out of [`SurfpoolJitoRpc::process_bundle`] once a bundle ID is known
+    /// — including rejection — so `getInflightBundleStatuses` can report `Failed` rather than
+    /// treating a rejected bundle as if it never existed.
+    fn record_bundle(
+        &self,
+        bundle_id: &str,
+        signatures: &[Signature],
+        slot: Slot,
+        err: Option<TransactionError>,
+        tip_lamports: Option<u64>,
+        tip_signature: Option<Signature>,
+    ) {
+        self.bundle_registry.write().unwrap().insert(
+            bundle_id.to_string(),
+            BundleRecord {
+                signatures: signatures.to_vec(),
+                slot,
+                err,
+                tip_lamports,
+                tip_signature,
+            },
+        );
+    }
+
+    /// Core of `send_bundle`: validates the tip (if enforcement is on), stages and commits the
+    /// bundle, registers it, and returns its bundle ID together with the per-transaction
+    /// signatures produced. Shared with the Jito-flavored `sendTransaction` proxy, which wraps a
+    /// single transaction as a one-transaction bundle and runs it through the same path.
+    fn process_bundle(
+        &self,
+        ctx: &RunloopContext,
+        transactions: Vec<String>,
+        config: Option<RpcSendTransactionConfig>,
+    ) -> Result<(String, Vec<Signature>)> {
+        if transactions.is_empty() {
+            return Err(Error::invalid_params("Bundle cannot be empty"));
+        }
+
+        let max_bundle_len = self.max_bundle_len.unwrap_or(DEFAULT_MAX_BUNDLE_LEN);
+        Self::validate_bundle_length(transactions.len(), max_bundle_len)?;
+
+        // Pre-decode every transaction against the declared encoding before running anything, so
+        // a malformed entry is rejected atomically — before any state mutation — rather than
+        // after the first K valid transactions have already been committed. A bundle ID requires
+        // every transaction's own signature, so a malformed transaction can't be registered here;
+        // the caller gets the decode error directly instead.
+        let decoded = Self::decode_bundle(&transactions, &config)?;
+
+        // A transaction's first signature is its own ID and is fixed at signing time, so the
+        // bundle ID is known up front — independent of whether the bundle ever executes. That
+        // lets every remaining failure path below still register a (failed) bundle record.
+        let bundle_signatures = decoded
+            .iter()
+            .enumerate()
+            .map(|(idx, tx)| {
+                tx.signatures.first().copied().ok_or_else(|| {
+                    Error::invalid_params(format!("Bundle transaction {idx} has no signatures"))
+                })
+            })
+            .collect::<Result<Vec<Signature>>>()?;
+        let bundle_id = Self::compute_bundle_id(&bundle_signatures);
+
+        // Detect the tip unconditionally, regardless of enforcement: the bundle record always
+        // surfaces whatever tip was paid, even with tip enforcement off (the common case).
+        let (tip_lamports, paid_by) = Self::extract_tip(&decoded, &self.tip_config.tip_accounts);
+        let tip_signature = paid_by.and_then(|idx| bundle_signatures.get(idx).copied());
+
+        if self.tip_config.enforce_tips && tip_lamports < self.tip_config.min_tip_lamports {
+            self.record_bundle(
+                &bundle_id,
+                &bundle_signatures,
+                ctx.slot(),
+                Some(Self::unrepresentable_rejection_error()),
+                Some(tip_lamports),
+                tip_signature,
+            );
+            return Err(Error::invalid_params(format!(
+                "Bundle tip of {tip_lamports} lamports is below the required minimum of {}",
+                self.tip_config.min_tip_lamports
+            )));
+        }
+
+        let (tip_lamports, tip_signature) = if paid_by.is_some() {
+            (Some(tip_lamports), tip_signature)
+        } else {
+            (None, None)
+        };
+
+        // Stage the whole bundle against a checkpoint of the working SVM state. The checkpoint
+        // is copy-on-write over the accounts the bundle actually touches, so staging a large
+        // bundle doesn't require cloning the full account set.
+        let checkpoint = ctx.checkpoint();
+        if let Err(e) = Self::execute_staged(&checkpoint, &transactions, &config) {
+            self.record_bundle(
+                &bundle_id,
+                &bundle_signatures,
+                ctx.slot(),
+                Some(Self::unrepresentable_rejection_error()),
+                tip_lamports,
+                tip_signature,
+            );
+            return Err(e);
+        }
+
+        // Every transaction in the bundle succeeded against the checkpoint — commit it to
+        // canonical state in one shot.
+        ctx.commit_checkpoint(checkpoint);
+
+        self.record_bundle(
+            &bundle_id,
+            &bundle_signatures,
+            ctx.slot(),
+            None,
+            tip_lamports,
+            tip_signature,
+        );
+
+        Ok((bundle_id, bundle_signatures))
+    }
+
+    /// Maps a registry lookup to the `(status, landed_slot)` pair `getInflightBundleStatuses`
+    /// reports for it: no record is `Invalid`, a record with no error is `Landed`, and a record
+    /// with an error is `Failed`. Split out from `get_inflight_bundle_statuses` so this mapping
+    /// can be unit-tested without a `RunloopContext`.
+    fn inflight_status_of(record: Option<&BundleRecord>) -> (InflightBundleStatus, Option<Slot>) {
+        match record {
+            Some(record) if Self::landed(record) => {
+                (InflightBundleStatus::Landed, Some(record.slot))
+            }
+            Some(record) => (InflightBundleStatus::Failed, Some(record.slot)),
+            None => (InflightBundleStatus::Invalid, None),
+        }
+    }
+
+    /// A bundle landed iff its record has no error. Shared by `get_bundle_statuses` (to decide
+    /// whether to report a `confirmation_status` at all) and `inflight_status_of` (to pick
+    /// `Landed` vs. `Failed`), so the two can't drift into reporting a rejected bundle as landed
+    /// in one place and failed in the other.
+    fn landed(record: &BundleRecord) -> bool {
+        record.err.is_none()
+    }
+
+    /// Resolves the Jito-flavored `sendTransaction` config into `(bundle_only, send_config)`:
+    /// `bundle_only` defaults to `false`, and `skip_preflight` is always forced to `true`
+    /// regardless of what the caller asked for, matching Block Engine behavior.
+    fn resolve_jito_send_config(
+        opts: RpcJitoSendTransactionConfig,
+    ) -> (bool, Option<RpcSendTransactionConfig>) {
+        let bundle_only = opts.bundle_only.unwrap_or(false);
+        let send_config = RpcSendTransactionConfig {
+            skip_preflight: true,
+            ..opts.send_config
+        };
+        (bundle_only, Some(send_config))
+    }
+
+    /// Placeholder `TransactionError` for bundle rejections that don't come from a single,
+    /// structured SVM failure (an insufficient tip, or a staged transaction that errored out via
+    /// a jsonrpc-level `Error` rather than a `TransactionError`). `err.is_some()` is what
+    /// `getInflightBundleStatuses` relies on to report `Failed`; the real rejection reason is
+    /// still returned to the caller of `send_bundle` itself via the jsonrpc error message.
+    fn unrepresentable_rejection_error() -> TransactionError {
+        TransactionError::InstructionError(0, InstructionError::GenericError)
+    }
+}
 
 impl Jito for SurfpoolJitoRpc {
     type Metadata = Option<RunloopContext>;
@@ -67,58 +619,432 @@ impl Jito for SurfpoolJitoRpc {
         transactions: Vec<String>,
         config: Option<RpcSendTransactionConfig>,
     ) -> Result<String> {
+        let Some(ctx) = &meta else {
+            return Err(RpcCustomError::NodeUnhealthy {
+                num_slots_behind: None,
+            }
+            .into());
+        };
+
+        let (bundle_id, _signatures) = self.process_bundle(ctx, transactions, config)?;
+        Ok(bundle_id)
+    }
+
+    fn get_bundle_statuses(
+        &self,
+        meta: Self::Metadata,
+        bundle_ids: Vec<String>,
+    ) -> Result<RpcResponse<Vec<Option<RpcBundleStatus>>>> {
+        let Some(ctx) = &meta else {
+            return Err(RpcCustomError::NodeUnhealthy {
+                num_slots_behind: None,
+            }
+            .into());
+        };
+
+        let registry = self.bundle_registry.read().unwrap();
+        let value = bundle_ids
+            .iter()
+            .map(|bundle_id| {
+                registry.get(bundle_id).map(|record| RpcBundleStatus {
+                    bundle_id: bundle_id.clone(),
+                    transactions: record.signatures.iter().map(|sig| sig.to_string()).collect(),
+                    slot: record.slot,
+                    confirmation_status: Self::landed(record)
+                        .then_some(TransactionConfirmationStatus::Finalized),
+                    err: record.err.clone(),
+                    tip_lamports: record.tip_lamports,
+                    tip_signature: record.tip_signature.map(|sig| sig.to_string()),
+                })
+            })
+            .collect();
+
+        Ok(RpcResponse {
+            context: RpcResponseContext::new(ctx.slot()),
+            value,
+        })
+    }
+
+    fn get_inflight_bundle_statuses(
+        &self,
+        meta: Self::Metadata,
+        bundle_ids: Vec<String>,
+    ) -> Result<RpcResponse<Vec<RpcInflightBundleStatus>>> {
+        let Some(ctx) = &meta else {
+            return Err(RpcCustomError::NodeUnhealthy {
+                num_slots_behind: None,
+            }
+            .into());
+        };
+
+        let registry = self.bundle_registry.read().unwrap();
+        let value = bundle_ids
+            .into_iter()
+            .map(|bundle_id| {
+                let (status, landed_slot) = Self::inflight_status_of(registry.get(&bundle_id));
+                RpcInflightBundleStatus {
+                    bundle_id,
+                    status,
+                    landed_slot,
+                }
+            })
+            .collect();
+
+        Ok(RpcResponse {
+            context: RpcResponseContext::new(ctx.slot()),
+            value,
+        })
+    }
+
+    fn simulate_bundle(
+        &self,
+        meta: Self::Metadata,
+        transactions: Vec<String>,
+        config: Option<RpcSimulateBundleConfig>,
+    ) -> Result<RpcResponse<RpcSimulateBundleResult>> {
         if transactions.is_empty() {
             return Err(Error::invalid_params("Bundle cannot be empty"));
         }
 
-        let Some(_ctx) = &meta else {
+        let Some(ctx) = &meta else {
             return Err(RpcCustomError::NodeUnhealthy {
                 num_slots_behind: None,
             }
             .into());
         };
 
+        let sig_verify = config.as_ref().and_then(|c| c.sig_verify).unwrap_or(true);
+        let encoding = config.as_ref().and_then(|c| c.encoding);
         let full_rpc = SurfpoolFullRpc;
-        let mut bundle_signatures = Vec::new();
 
-        // Process each transaction in the bundle sequentially using Full RPC
-        // Force skip_preflight to match Jito Block Engine behavior (no simulation on sendBundle)
+        // A throwaway checkpoint, never committed: every transaction is really executed against
+        // it (so later transactions see earlier ones' effects), but canonical state never
+        // observes any of it.
+        let checkpoint = ctx.checkpoint();
+        let mut transaction_results = Vec::with_capacity(transactions.len());
+
         for (idx, tx_data) in transactions.iter().enumerate() {
-            let bundle_config = Some(RpcSendTransactionConfig {
-                skip_preflight: true,
-                ..config.clone().unwrap_or_default()
+            let send_config = Some(RpcSendTransactionConfig {
+                skip_preflight: !sig_verify,
+                encoding,
+                ..Default::default()
             });
-            // Delegate to Full RPC's sendTransaction method
-            match full_rpc.send_transaction(meta.clone(), tx_data.clone(), bundle_config) {
+            match full_rpc.send_transaction(Some(checkpoint.clone()), tx_data.clone(), send_config)
+            {
                 Ok(signature_str) => {
-                    // Parse the signature to collect for bundle ID calculation
-                    let signature = Signature::from_str(&signature_str).map_err(|e| {
-                        Error::invalid_params(format!("Failed to parse signature: {e}"))
-                    })?;
-                    bundle_signatures.push(signature);
+                    let executed = full_rpc
+                        .get_transaction(Some(checkpoint.clone()), signature_str, None)
+                        .ok()
+                        .flatten();
+                    let tx_meta = executed.and_then(|tx| tx.meta);
+                    transaction_results.push(RpcSimulateBundleTransactionResult {
+                        err: None,
+                        logs: tx_meta.as_ref().and_then(|m| m.log_messages.clone()),
+                        units_consumed: tx_meta.as_ref().and_then(|m| m.compute_units_consumed),
+                        return_data: tx_meta.and_then(|m| m.return_data.clone()),
+                    });
                 }
                 Err(e) => {
-                    // Add bundle transaction index to error message
-                    return Err(Error {
-                        code: e.code,
-                        message: format!("Bundle transaction {} failed: {}", idx, e.message),
-                        data: e.data,
+                    // `send_transaction` only surfaces a jsonrpc `Error`, not a structured
+                    // `TransactionError`, so the best we can do here is report the generic
+                    // instruction-error shape; the real message and which transaction in the
+                    // bundle it came from are preserved in `logs` instead of being discarded.
+                    transaction_results.push(RpcSimulateBundleTransactionResult {
+                        err: Some(TransactionError::InstructionError(
+                            0,
+                            InstructionError::GenericError,
+                        )),
+                        logs: Some(vec![format!(
+                            "Bundle transaction {idx} failed: {}",
+                            e.message
+                        )]),
+                        units_consumed: None,
+                        return_data: None,
                     });
+                    // A bundle is sequential: once one transaction fails, the rest never run.
+                    break;
                 }
             }
         }
 
-        // Calculate bundle ID by hashing comma-separated signatures (Jito-compatible)
-        // https://github.com/jito-foundation/jito-solana/blob/master/sdk/src/bundle/mod.rs#L21
-        use sha2::{Digest, Sha256};
-        let concatenated_signatures = bundle_signatures
-            .iter()
-            .map(|sig| sig.to_string())
-            .collect::<Vec<_>>()
-            .join(",");
-        let mut hasher = Sha256::new();
-        hasher.update(concatenated_signatures.as_bytes());
-        let bundle_id = hasher.finalize();
-        Ok(hex::encode(bundle_id))
+        // Drop the checkpoint without committing — nothing we did above is observable.
+
+        Ok(RpcResponse {
+            context: RpcResponseContext::new(ctx.slot()),
+            value: RpcSimulateBundleResult {
+                transaction_results,
+            },
+        })
+    }
+
+    fn send_transaction(
+        &self,
+        meta: Self::Metadata,
+        transaction: String,
+        config: Option<RpcJitoSendTransactionConfig>,
+    ) -> Result<String> {
+        let Some(ctx) = &meta else {
+            return Err(RpcCustomError::NodeUnhealthy {
+                num_slots_behind: None,
+            }
+            .into());
+        };
+
+        let (bundle_only, send_config) = Self::resolve_jito_send_config(config.unwrap_or_default());
+
+        let (_bundle_id, signatures) =
+            self.process_bundle(ctx, vec![transaction], send_config)?;
+        let signature = signatures
+            .into_iter()
+            .next()
+            .ok_or_else(|| Error::invalid_params("Bundle produced no signature"))?;
+
+        if !bundle_only {
+            // The bundle path already committed the transaction to canonical state, so there's
+            // nothing left to execute here — just make sure it's also visible through the
+            // standalone-transaction bookkeeping a plain `sendTransaction` caller expects.
+            ctx.track_standalone_signature(signature);
+        }
+
+        Ok(signature.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use solana_sdk::hash::Hash;
+    use solana_sdk::signature::Keypair;
+    use solana_sdk::system_transaction;
+
+    use super::*;
+
+    #[test]
+    fn extract_tip_sums_transfers_to_designated_accounts() {
+        let payer = Keypair::new();
+        let tip_account = Pubkey::new_unique();
+        let tip_accounts = HashSet::from([tip_account]);
+
+        let tx = VersionedTransaction::from(system_transaction::transfer(
+            &payer,
+            &tip_account,
+            5_000,
+            Hash::default(),
+        ));
+
+        let (total, paid_by) = SurfpoolJitoRpc::extract_tip(&[tx], &tip_accounts);
+        assert_eq!(total, 5_000);
+        assert_eq!(paid_by, Some(0));
+    }
+
+    #[test]
+    fn extract_tip_ignores_transfers_to_non_tip_accounts() {
+        let payer = Keypair::new();
+        let other = Pubkey::new_unique();
+        let tip_accounts = HashSet::new();
+
+        let tx = VersionedTransaction::from(system_transaction::transfer(
+            &payer,
+            &other,
+            5_000,
+            Hash::default(),
+        ));
+
+        let (total, paid_by) = SurfpoolJitoRpc::extract_tip(&[tx], &tip_accounts);
+        assert_eq!(total, 0);
+        assert_eq!(paid_by, None);
+    }
+
+    #[test]
+    fn extract_tip_reports_the_first_transaction_that_paid() {
+        let payer = Keypair::new();
+        let tip_account = Pubkey::new_unique();
+        let tip_accounts = HashSet::from([tip_account]);
+
+        let untipped = VersionedTransaction::from(system_transaction::transfer(
+            &payer,
+            &Pubkey::new_unique(),
+            1_000,
+            Hash::default(),
+        ));
+        let tipped = VersionedTransaction::from(system_transaction::transfer(
+            &payer,
+            &tip_account,
+            2_500,
+            Hash::default(),
+        ));
+
+        let (total, paid_by) = SurfpoolJitoRpc::extract_tip(&[untipped, tipped], &tip_accounts);
+        assert_eq!(total, 2_500);
+        assert_eq!(paid_by, Some(1));
+    }
+
+    #[test]
+    fn validate_bundle_length_accepts_at_the_limit() {
+        assert!(SurfpoolJitoRpc::validate_bundle_length(5, 5).is_ok());
+    }
+
+    #[test]
+    fn validate_bundle_length_rejects_over_the_limit() {
+        assert!(SurfpoolJitoRpc::validate_bundle_length(6, 5).is_err());
+    }
+
+    #[test]
+    fn decode_bundle_reports_the_malformed_transaction_by_index() {
+        let tx = dummy_transaction();
+        let valid = bs58::encode(bincode::serialize(&tx).unwrap()).into_string();
+        let transactions = vec![valid, "not a real transaction".to_string()];
+
+        let err = SurfpoolJitoRpc::decode_bundle(&transactions, &None).unwrap_err();
+        assert!(err.message.contains("Bundle transaction 1"));
+    }
+
+    #[test]
+    fn decode_bundle_decodes_every_transaction_in_order() {
+        let first = dummy_transaction();
+        let second = dummy_transaction();
+        let transactions = vec![
+            bs58::encode(bincode::serialize(&first).unwrap()).into_string(),
+            bs58::encode(bincode::serialize(&second).unwrap()).into_string(),
+        ];
+
+        let decoded = SurfpoolJitoRpc::decode_bundle(&transactions, &None).unwrap();
+        assert_eq!(decoded[0].signatures, first.signatures);
+        assert_eq!(decoded[1].signatures, second.signatures);
+    }
+
+    #[test]
+    fn resolve_jito_send_config_defaults_bundle_only_to_false() {
+        let (bundle_only, _) = SurfpoolJitoRpc::resolve_jito_send_config(
+            RpcJitoSendTransactionConfig::default(),
+        );
+        assert!(!bundle_only);
+    }
+
+    #[test]
+    fn resolve_jito_send_config_honors_explicit_bundle_only() {
+        let (bundle_only, _) = SurfpoolJitoRpc::resolve_jito_send_config(RpcJitoSendTransactionConfig {
+            bundle_only: Some(true),
+            ..Default::default()
+        });
+        assert!(bundle_only);
+    }
+
+    #[test]
+    fn resolve_jito_send_config_always_forces_skip_preflight() {
+        let (_, send_config) = SurfpoolJitoRpc::resolve_jito_send_config(RpcJitoSendTransactionConfig {
+            send_config: RpcSendTransactionConfig {
+                skip_preflight: false,
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+        assert!(send_config.unwrap().skip_preflight);
+    }
+
+    fn dummy_transaction() -> VersionedTransaction {
+        VersionedTransaction::from(system_transaction::transfer(
+            &Keypair::new(),
+            &Pubkey::new_unique(),
+            1_000,
+            Hash::default(),
+        ))
+    }
+
+    #[test]
+    fn decode_transaction_defaults_to_base58() {
+        let tx = dummy_transaction();
+        let encoded = bs58::encode(bincode::serialize(&tx).unwrap()).into_string();
+
+        let decoded = SurfpoolJitoRpc::decode_transaction(&encoded, &None).unwrap();
+        assert_eq!(decoded.signatures, tx.signatures);
+    }
+
+    #[test]
+    fn decode_transaction_honors_base64_encoding() {
+        let tx = dummy_transaction();
+        let encoded =
+            base64::engine::general_purpose::STANDARD.encode(bincode::serialize(&tx).unwrap());
+        let config = Some(RpcSendTransactionConfig {
+            encoding: Some(UiTransactionEncoding::Base64),
+            ..Default::default()
+        });
+
+        let decoded = SurfpoolJitoRpc::decode_transaction(&encoded, &config).unwrap();
+        assert_eq!(decoded.signatures, tx.signatures);
+    }
+
+    #[test]
+    fn decode_transaction_rejects_base64_data_under_the_base58_default() {
+        let tx = dummy_transaction();
+        let encoded =
+            base64::engine::general_purpose::STANDARD.encode(bincode::serialize(&tx).unwrap());
+
+        assert!(SurfpoolJitoRpc::decode_transaction(&encoded, &None).is_err());
+    }
+
+    fn record(err: Option<TransactionError>) -> BundleRecord {
+        BundleRecord {
+            signatures: vec![Signature::default()],
+            slot: 42,
+            err,
+            tip_lamports: None,
+            tip_signature: None,
+        }
+    }
+
+    #[test]
+    fn inflight_status_of_reports_landed_for_a_clean_record() {
+        let (status, landed_slot) =
+            SurfpoolJitoRpc::inflight_status_of(Some(&record(None)));
+        assert_eq!(status, InflightBundleStatus::Landed);
+        assert_eq!(landed_slot, Some(42));
+    }
+
+    #[test]
+    fn inflight_status_of_reports_failed_for_a_record_with_an_error() {
+        let (status, landed_slot) = SurfpoolJitoRpc::inflight_status_of(Some(&record(Some(
+            TransactionError::InstructionError(0, InstructionError::GenericError),
+        ))));
+        assert_eq!(status, InflightBundleStatus::Failed);
+        // The bundle was still processed and has a slot, even though it failed.
+        assert_eq!(landed_slot, Some(42));
+    }
+
+    #[test]
+    fn inflight_status_of_reports_invalid_for_an_unknown_bundle() {
+        let (status, landed_slot) = SurfpoolJitoRpc::inflight_status_of(None);
+        assert_eq!(status, InflightBundleStatus::Invalid);
+        assert_eq!(landed_slot, None);
+    }
+
+    #[test]
+    fn record_bundle_is_queryable_immediately_after_a_rejection() {
+        let rpc = SurfpoolJitoRpc::default();
+        let signatures = vec![Signature::default()];
+        rpc.record_bundle(
+            "test-bundle",
+            &signatures,
+            7,
+            Some(SurfpoolJitoRpc::unrepresentable_rejection_error()),
+            None,
+            None,
+        );
+
+        let registry = rpc.bundle_registry.read().unwrap();
+        let stored = registry.get("test-bundle").expect("bundle should be recorded");
+        assert!(stored.err.is_some());
+        assert_eq!(stored.slot, 7);
+    }
+
+    #[test]
+    fn landed_is_false_for_a_record_with_an_error() {
+        assert!(!SurfpoolJitoRpc::landed(&record(Some(
+            TransactionError::InstructionError(0, InstructionError::GenericError)
+        ))));
+    }
+
+    #[test]
+    fn landed_is_true_for_a_clean_record() {
+        assert!(SurfpoolJitoRpc::landed(&record(None)));
     }
 }